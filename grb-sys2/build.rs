@@ -11,6 +11,12 @@ enum Error {
   GurobiHomeNotGiven,
   GurobiClNotFound,
   CannotParseGurobiVersion,
+  #[cfg(feature = "bindgen")]
+  HeaderNotFound(PathBuf),
+  #[cfg(feature = "bindgen")]
+  BindgenFailed(String),
+  #[cfg(feature = "bindgen")]
+  BindgenWriteFailed(std::io::Error),
 }
 
 impl Display for Error {
@@ -24,6 +30,17 @@ impl Display for Error {
       Error::GurobiHomeNotGiven => f.write_str("GUROBI_HOME not set"),
       Error::GurobiClNotFound => f.write_str("gurobi_cl not found"),
       Error::CannotParseGurobiVersion => f.write_str("Cannot get Gurobi version"),
+      #[cfg(feature = "bindgen")]
+      Error::HeaderNotFound(p) => f.write_fmt(format_args!("gurobi_c.h not found at {:?}", p)),
+      #[cfg(feature = "bindgen")]
+      Error::BindgenFailed(e) => f.write_fmt(format_args!(
+        "Unable to generate bindings from gurobi_c.h: {}",
+        e
+      )),
+      #[cfg(feature = "bindgen")]
+      Error::BindgenWriteFailed(e) => {
+        f.write_fmt(format_args!("Unable to write generated bindings: {}", e))
+      }
     }
   }
 }
@@ -43,7 +60,7 @@ fn get_gurobi_home() -> Result<PathBuf, Error> {
   path.canonicalize().map_err(|_| Error::DoesNotExist(path))
 }
 
-fn get_gurobi_library(gurobi_home: &Path) -> Result<String, Error> {
+fn get_gurobi_version(gurobi_home: &Path) -> Result<(String, String), Error> {
   let gurobi_cl = gurobi_home.join("bin").join("gurobi_cl");
 
   if !gurobi_cl.exists() {
@@ -60,16 +77,45 @@ fn get_gurobi_library(gurobi_home: &Path) -> Result<String, Error> {
   let re = Regex::new(r"Gurobi Optimizer version (\d+).(\d+).(\d+)").unwrap();
   let captures = re.captures(&output).unwrap();
 
-  let major = &captures[1];
-  let minor = &captures[2];
+  Ok((captures[1].to_string(), captures[2].to_string()))
+}
 
-  Ok(format!("gurobi{}{}", major, minor))
+fn get_gurobi_library(major: &str, minor: &str) -> String {
+  format!("gurobi{}{}", major, minor)
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings(gurobi_home: &Path, major: &str, minor: &str) -> Result<(), Error> {
+  let header = gurobi_home.join("include").join("gurobi_c.h");
+
+  if !header.exists() {
+    return Err(Error::HeaderNotFound(header));
+  }
+
+  let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+  println!("cargo:rerun-if-changed={}", header.display());
+
+  let bindings = bindgen::Builder::default()
+    .header(header.to_string_lossy())
+    .clang_arg(format!("-DGRB_VERSION_MAJOR={}", major))
+    .clang_arg(format!("-DGRB_VERSION_MINOR={}", minor))
+    .parse_callbacks(Box::new(bindgen::CargoCallbacks))
+    .generate()
+    .map_err(|e| Error::BindgenFailed(e.to_string()))?;
+
+  bindings
+    .write_to_file(out_dir.join("bindings.rs"))
+    .map_err(Error::BindgenWriteFailed)?;
+
+  Ok(())
 }
 
 fn try_main() -> Result<(), Error> {
   let gurobi_home = get_gurobi_home()?;
 
-  let library = get_gurobi_library(&gurobi_home)?;
+  let (major, minor) = get_gurobi_version(&gurobi_home)?;
+  let library = get_gurobi_library(&major, &minor);
 
   println!(
     "cargo:rustc-link-search=native={}",
@@ -77,6 +123,9 @@ fn try_main() -> Result<(), Error> {
   );
   println!("cargo:rustc-link-lib=dylib={}", library);
 
+  #[cfg(feature = "bindgen")]
+  generate_bindings(&gurobi_home, &major, &minor)?;
+
   Ok(())
 }
 
@@ -0,0 +1,35 @@
+// Hand-maintained FFI declarations for the Gurobi C API.
+//
+// These are kept in sync by hand across Gurobi releases. When the `bindgen`
+// feature is enabled, the generated bindings in `$OUT_DIR/bindings.rs` are
+// used instead of this file.
+
+use std::os::raw::{c_char, c_double, c_int};
+
+#[repr(C)]
+pub struct GRBenv {
+  _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct GRBmodel {
+  _private: [u8; 0],
+}
+
+extern "C" {
+  pub fn GRBloadenv(envP: *mut *mut GRBenv, logfilename: *const c_char) -> c_int;
+  pub fn GRBfreeenv(env: *mut GRBenv);
+  pub fn GRBnewmodel(
+    env: *mut GRBenv,
+    modelP: *mut *mut GRBmodel,
+    Pname: *const c_char,
+    numvars: c_int,
+    obj: *const c_double,
+    lb: *const c_double,
+    ub: *const c_double,
+    vtype: *const c_char,
+    varnames: *const *const c_char,
+  ) -> c_int;
+  pub fn GRBfreemodel(model: *mut GRBmodel);
+  pub fn GRBoptimize(model: *mut GRBmodel) -> c_int;
+}
@@ -0,0 +1,13 @@
+//! Raw FFI bindings to the Gurobi C API.
+//!
+//! By default these are the hand-maintained declarations in `bindings.rs`.
+//! Enable the `bindgen` feature to generate them from `gurobi_c.h` at build
+//! time instead, so new Gurobi releases are picked up with no code changes.
+
+#![allow(non_camel_case_types, non_snake_case)]
+
+#[cfg(feature = "bindgen")]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(not(feature = "bindgen"))]
+include!("bindings.rs");